@@ -1,13 +1,16 @@
 use chrono::offset::Local;
 use chrono::DateTime;
+use globset::{Glob, GlobMatcher};
+use log::{error, info, warn};
 use python_input::input;
+use rayon::iter::{IntoParallelRefIterator, ParallelBridge, ParallelIterator};
 use std::ffi::{OsStr, OsString};
-use std::fs::{self, DirEntry};
+use std::fs;
 use std::path::{Path, PathBuf};
 use std::time::{Duration, SystemTime};
+use walkdir::WalkDir;
 
 const CLI_NAME: &str = "something";
-const DAYS_PER_WEEK: u64 = 7;
 const HOURS_PER_DAY: u64 = 24;
 const MINUTES_PER_HOUR: u64 = 60;
 const SECONDS_PER_MINUTE: u64 = 60;
@@ -20,13 +23,50 @@ enum Error {
     ReadFileError,
     TimeSubtractionError,
     DeleteFailed { filename: OsString },
+    TrashFailed { filename: OsString },
+    PoolBuildFailed,
     Cancelled,
 }
 
 #[derive(Debug)]
 struct Args {
     path: PathBuf,
-    duration: Duration,
+    duration: Option<Duration>,
+    max_size: Option<u64>,
+    purge: bool,
+    recursive: bool,
+    dry_run: bool,
+    time_field: TimeField,
+    jobs: usize,
+    include: Option<GlobMatcher>,
+    exclude: Option<GlobMatcher>,
+}
+
+/// Outcome of a (possibly parallel) deletion pass: how many entries were
+/// removed successfully, and which ones failed along with why. `previewed`
+/// is set when nothing was actually touched on disk (`--dry-run`), so
+/// callers can tell a real removal count from a hypothetical one.
+struct DeletionSummary {
+    succeeded: usize,
+    failures: Vec<(PathBuf, Error)>,
+    previewed: bool,
+}
+
+/// Which filesystem timestamp pruning decisions are based on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TimeField {
+    Accessed,
+    Created,
+    Modified,
+}
+
+/// A filesystem entry that has survived the include/exclude filters, along with
+/// the timestamp and size the pruning decisions are made against. Directories
+/// carry the recursive sum of the sizes of the files they contain.
+struct Candidate {
+    path: PathBuf,
+    modified: SystemTime,
+    size: u64,
 }
 
 type Result<T> = core::result::Result<T, Error>;
@@ -40,9 +80,65 @@ fn get_args<'a>() -> Result<Args> {
     let duration_arg = clap::Arg::new("duration")
         .short('d')
         .long("duration")
-        .required(true);
+        .required(false)
+        .help("Age threshold, e.g. 30m, 12h, 1d12h, 2w");
+
+    let max_size_arg = clap::Arg::new("max_size")
+        .long("max-size")
+        .required(false)
+        .help(
+            "Evict the oldest entries until path fits under this size (e.g. 500MB, 2GB). \
+             With --recursive, eviction only considers individual files, since whole \
+             directories are never deleted in that mode.",
+        );
+
+    let purge_arg = clap::Arg::new("purge")
+        .long("purge")
+        .action(clap::ArgAction::SetTrue)
+        .help("Permanently delete matched entries instead of sending them to the trash/recycle bin");
+
+    let recursive_arg = clap::Arg::new("recursive")
+        .short('r')
+        .long("recursive")
+        .action(clap::ArgAction::SetTrue)
+        .help("Also clean entries nested in subdirectories of path");
+
+    let dry_run_arg = clap::Arg::new("dry_run")
+        .short('n')
+        .long("dry-run")
+        .action(clap::ArgAction::SetTrue)
+        .help("Show what would be removed without touching disk");
+
+    let time_field_arg = clap::Arg::new("time_field")
+        .long("time-field")
+        .default_value("mtime")
+        .help("Which timestamp to prune by: atime, mtime, or ctime");
+
+    let jobs_arg = clap::Arg::new("jobs")
+        .short('j')
+        .long("jobs")
+        .required(false)
+        .help("Number of worker threads to scan and delete with (default: number of CPUs)");
+
+    let include_arg = clap::Arg::new("include")
+        .long("include")
+        .help("Only consider entries whose path matches this glob");
 
-    let command = clap::Command::new(CLI_NAME).arg(path_arg).arg(duration_arg);
+    let exclude_arg = clap::Arg::new("exclude")
+        .long("exclude")
+        .help("Skip entries whose path matches this glob");
+
+    let command = clap::Command::new(CLI_NAME)
+        .arg(path_arg)
+        .arg(duration_arg)
+        .arg(max_size_arg)
+        .arg(purge_arg)
+        .arg(recursive_arg)
+        .arg(dry_run_arg)
+        .arg(time_field_arg)
+        .arg(jobs_arg)
+        .arg(include_arg)
+        .arg(exclude_arg);
     let matches = command.get_matches();
 
     let path_str = matches
@@ -55,119 +151,546 @@ fn get_args<'a>() -> Result<Args> {
         .get_raw("duration")
         .and_then(Iterator::last)
         .and_then(OsStr::to_str)
-        .expect("duration is required");
-
-    let duration = match duration.chars().last() {
-        Some('d') => {
-            let number_of_days: u64 =
-                duration
-                    .trim_end_matches('d')
-                    .parse()
-                    .or(Err(Error::InvalidArgument {
-                        name: "duration".to_string(),
-                    }))?;
-
-            Duration::from_secs(
-                number_of_days * SECONDS_PER_MINUTE * MINUTES_PER_HOUR * HOURS_PER_DAY,
-            )
-        }
+        .map(|duration| {
+            humantime::parse_duration(duration).or(Err(Error::InvalidArgument {
+                name: "duration".to_string(),
+            }))
+        })
+        .transpose()?;
 
-        Some('w') => {
-            let number_of_weeks: u64 =
-                duration
-                    .trim_end_matches('w')
-                    .parse()
-                    .or(Err(Error::InvalidArgument {
-                        name: "duration".to_string(),
-                    }))?;
-
-            Duration::from_secs(
-                number_of_weeks
-                    * SECONDS_PER_MINUTE
-                    * MINUTES_PER_HOUR
-                    * HOURS_PER_DAY
-                    * DAYS_PER_WEEK,
-            )
-        }
-        _ => Err(Error::InvalidArgument {
+    let max_size = matches
+        .get_one::<String>("max_size")
+        .map(|value| parse_size(value))
+        .transpose()?;
+
+    if duration.is_none() && max_size.is_none() {
+        Err(Error::InvalidArgument {
             name: "duration".to_string(),
+        })?
+    }
+
+    let purge = matches.get_flag("purge");
+    let recursive = matches.get_flag("recursive");
+    let dry_run = matches.get_flag("dry_run");
+
+    let time_field = match matches
+        .get_one::<String>("time_field")
+        .map(String::as_str)
+        .expect("time_field has a default value")
+    {
+        "atime" => TimeField::Accessed,
+        "mtime" => TimeField::Modified,
+        "ctime" => TimeField::Created,
+        _ => Err(Error::InvalidArgument {
+            name: "time_field".to_string(),
         })?,
     };
 
-    Ok(Args { path, duration })
+    let jobs = matches
+        .get_one::<String>("jobs")
+        .map(|value| {
+            value.parse::<usize>().or(Err(Error::InvalidArgument {
+                name: "jobs".to_string(),
+            }))
+        })
+        .transpose()?
+        .unwrap_or_else(|| {
+            std::thread::available_parallelism()
+                .map(|n| n.get())
+                .unwrap_or(1)
+        });
+
+    let include = matches
+        .get_one::<String>("include")
+        .map(|pattern| compile_glob(pattern, "include"))
+        .transpose()?;
+    let exclude = matches
+        .get_one::<String>("exclude")
+        .map(|pattern| compile_glob(pattern, "exclude"))
+        .transpose()?;
+
+    Ok(Args {
+        path,
+        duration,
+        max_size,
+        purge,
+        recursive,
+        dry_run,
+        time_field,
+        jobs,
+        include,
+        exclude,
+    })
 }
 
-fn main_script() -> Result<usize> {
-    let Args { path, duration } = get_args()?;
+/// Parses sizes like `500MB`, `2GB` or a bare byte count into a byte count.
+fn parse_size(value: &str) -> Result<u64> {
+    let upper = value.trim().to_uppercase();
 
-    let threshold_time = SystemTime::now()
-        .checked_sub(duration)
-        .ok_or(Error::TimeSubtractionError)?;
-    let threshold_time_datetime: DateTime<Local> = threshold_time.into();
-    let threshold_time_str = threshold_time_datetime.format("%d/%m/%Y %T");
+    let (digits, multiplier): (&str, u64) = if let Some(digits) = upper.strip_suffix("TB") {
+        (digits, 1024u64.pow(4))
+    } else if let Some(digits) = upper.strip_suffix("GB") {
+        (digits, 1024u64.pow(3))
+    } else if let Some(digits) = upper.strip_suffix("MB") {
+        (digits, 1024u64.pow(2))
+    } else if let Some(digits) = upper.strip_suffix("KB") {
+        (digits, 1024)
+    } else if let Some(digits) = upper.strip_suffix('B') {
+        (digits, 1)
+    } else {
+        (upper.as_str(), 1)
+    };
+
+    let amount: u64 = digits.trim().parse().or(Err(Error::InvalidArgument {
+        name: "max_size".to_string(),
+    }))?;
+
+    amount.checked_mul(multiplier).ok_or(Error::InvalidArgument {
+        name: "max_size".to_string(),
+    })
+}
+
+fn compile_glob(pattern: &str, name: &str) -> Result<GlobMatcher> {
+    Glob::new(pattern)
+        .map(|glob| glob.compile_matcher())
+        .or(Err(Error::InvalidArgument {
+            name: name.to_string(),
+        }))
+}
+
+fn matches_filters(
+    path: &Path,
+    include: &Option<GlobMatcher>,
+    exclude: &Option<GlobMatcher>,
+) -> bool {
+    if let Some(exclude) = exclude {
+        if exclude.is_match(path) {
+            return false;
+        }
+    }
+
+    if let Some(include) = include {
+        if !include.is_match(path) {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Renders a [`Duration`] as a compact age string like `3d4h` or `45s`.
+fn format_age(age: Duration) -> String {
+    let total_secs = age.as_secs();
+    let days = total_secs / (SECONDS_PER_MINUTE * MINUTES_PER_HOUR * HOURS_PER_DAY);
+    let hours = (total_secs / (SECONDS_PER_MINUTE * MINUTES_PER_HOUR)) % HOURS_PER_DAY;
+    let minutes = (total_secs / SECONDS_PER_MINUTE) % MINUTES_PER_HOUR;
+    let seconds = total_secs % SECONDS_PER_MINUTE;
+
+    let mut rendered = String::new();
+    if days > 0 {
+        rendered.push_str(&format!("{days}d"));
+    }
+    if hours > 0 {
+        rendered.push_str(&format!("{hours}h"));
+    }
+    if minutes > 0 && days == 0 {
+        rendered.push_str(&format!("{minutes}m"));
+    }
+    if rendered.is_empty() {
+        rendered.push_str(&format!("{seconds}s"));
+    }
+    rendered
+}
+
+/// Size in bytes an entry would free up if removed. Directories are summed recursively.
+fn entry_size(path: &Path) -> Result<u64> {
+    if path.is_dir() {
+        Ok(WalkDir::new(path)
+            .into_iter()
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.file_type().is_file())
+            .filter_map(|entry| entry.metadata().ok())
+            .map(|metadata| metadata.len())
+            .sum())
+    } else {
+        fs::metadata(path)
+            .or(Err(Error::ReadFileError))
+            .map(|metadata| metadata.len())
+    }
+}
+
+/// Reads the requested timestamp off `metadata`, falling back to mtime (and warning)
+/// when the platform doesn't support the requested field.
+fn pick_time(metadata: &fs::Metadata, time_field: TimeField, entry_path: &Path) -> Result<SystemTime> {
+    let requested = match time_field {
+        TimeField::Accessed => metadata.accessed(),
+        TimeField::Created => metadata.created(),
+        TimeField::Modified => metadata.modified(),
+    };
+
+    match requested {
+        Ok(time) => Ok(time),
+        Err(_) if time_field != TimeField::Modified => {
+            warn!("{time_field:?} unsupported for {entry_path:?}, falling back to mtime");
+            metadata.modified().or(Err(Error::ReadFileError))
+        }
+        Err(_) => Err(Error::ReadFileError),
+    }
+}
+
+fn collect_candidates(
+    path: &Path,
+    recursive: bool,
+    time_field: TimeField,
+    include: &Option<GlobMatcher>,
+    exclude: &Option<GlobMatcher>,
+) -> Result<Vec<Candidate>> {
+    if recursive {
+        WalkDir::new(path)
+            .min_depth(1)
+            .into_iter()
+            // Prune descent into excluded directories entirely, rather than walking
+            // and stat-ing everything beneath them only to discard it afterwards.
+            .filter_entry(|entry| match exclude {
+                Some(exclude) if entry.file_type().is_dir() => !exclude.is_match(entry.path()),
+                _ => true,
+            })
+            .par_bridge()
+            .filter_map(|entry| {
+                let entry = match entry.or(Err(Error::ReadDirEntryError)) {
+                    Ok(entry) => entry,
+                    Err(err) => return Some(Err(err)),
+                };
+
+                // Directories are only descended into here; if one is old it is
+                // not deleted wholesale, only the individual files beneath it are.
+                if entry.file_type().is_dir() {
+                    return None;
+                }
+
+                let entry_path = entry.path().to_owned();
+                if !matches_filters(&entry_path, include, exclude) {
+                    return None;
+                }
+
+                // Directories never reach here (they're filtered out above), so the
+                // metadata already read for the timestamp also gives us the size.
+                let result = entry
+                    .metadata()
+                    .or(Err(Error::ReadFileError))
+                    .and_then(|metadata| {
+                        let modified = pick_time(&metadata, time_field, &entry_path)?;
+                        Ok(Candidate {
+                            size: metadata.len(),
+                            path: entry_path,
+                            modified,
+                        })
+                    });
+
+                if let Err(ref err) = result {
+                    warn!("skipping unreadable entry {:?}: {err:?}", entry.path());
+                    return None;
+                }
+
+                Some(result)
+            })
+            .collect()
+    } else {
+        fs::read_dir(path)
+            .or(Err(Error::ReadDirError {
+                dirname: path.as_os_str().to_owned(),
+            }))?
+            .par_bridge()
+            .filter_map(|entry| {
+                let entry = match entry.or(Err(Error::ReadDirEntryError)) {
+                    Ok(entry) => entry,
+                    Err(err) => return Some(Err(err)),
+                };
+
+                let entry_path = entry.path();
+                if !matches_filters(&entry_path, include, exclude) {
+                    return None;
+                }
+
+                // Reuse the metadata already read for the timestamp to get the size
+                // for plain files; directories still need the recursive sum.
+                let result = entry
+                    .metadata()
+                    .or(Err(Error::ReadFileError))
+                    .and_then(|metadata| {
+                        let modified = pick_time(&metadata, time_field, &entry_path)?;
+                        let size = if metadata.is_dir() {
+                            entry_size(&entry_path)?
+                        } else {
+                            metadata.len()
+                        };
+                        Ok(Candidate {
+                            size,
+                            path: entry_path,
+                            modified,
+                        })
+                    });
+
+                if let Err(ref err) = result {
+                    warn!("skipping unreadable entry {:?}: {err:?}", entry.path());
+                    return None;
+                }
+
+                Some(result)
+            })
+            .collect()
+    }
+}
+
+/// Evicts the oldest entries first until the remaining total size fits under `max_size`,
+/// returning the entries selected for eviction. `candidates` only ever contains whole
+/// directories in non-recursive mode (see `collect_candidates`); recursively-collected
+/// candidates are always individual files, so `--max-size --recursive` evicts file by
+/// file rather than removing a directory wholesale.
+fn size_trim(mut candidates: Vec<Candidate>, max_size: u64) -> Vec<Candidate> {
+    let mut total: u64 = candidates.iter().map(|candidate| candidate.size).sum();
+    if total <= max_size {
+        return Vec::new();
+    }
+
+    candidates.sort_by_key(|candidate| candidate.modified);
+
+    let mut evicted = Vec::new();
+    for candidate in candidates {
+        if total <= max_size {
+            break;
+        }
+        total = total.saturating_sub(candidate.size);
+        evicted.push(candidate);
+    }
+    evicted
+}
+
+fn main_script() -> Result<DeletionSummary> {
+    let Args {
+        path,
+        duration,
+        max_size,
+        purge,
+        recursive,
+        dry_run,
+        time_field,
+        jobs,
+        include,
+        exclude,
+    } = get_args()?;
+
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(jobs)
+        .build()
+        .or(Err(Error::PoolBuildFailed))?;
+
+    let threshold_time = duration
+        .map(|duration| SystemTime::now().checked_sub(duration).ok_or(Error::TimeSubtractionError))
+        .transpose()?;
     let path_str = path.clone().into_os_string();
 
-    let answer = input(&format!(
-        "Removing all files older than {threshold_time_str} in {path_str:?}. Enter \"y\" to confirm. "
-    ));
-    if answer != "y" {
-        Err(Error::Cancelled)?
-    }
-    let file_is_old_mapping: Result<Vec<(DirEntry, bool)>> = fs::read_dir(path)
-        .or(Err(Error::ReadDirError { dirname: path_str }))?
-        .map(|v| -> Result<(DirEntry, bool)> {
-            let file = v.or(Err(Error::ReadDirEntryError))?;
-            let modified = file
-                .metadata()
-                .or(Err(Error::ReadFileError))?
-                .modified()
-                .or(Err(Error::ReadFileError))?;
-            let is_old = modified.lt(&threshold_time);
-            Ok((file, is_old))
-        })
+    if !dry_run {
+        let action = if purge {
+            "permanently removing"
+        } else {
+            "trashing"
+        };
+        let criteria = match (threshold_time, max_size) {
+            (Some(threshold_time), Some(max_size)) => {
+                let threshold_time_datetime: DateTime<Local> = threshold_time.into();
+                format!(
+                    "all entries older than {}, then enough of the oldest remaining entries to fit under {max_size} bytes",
+                    threshold_time_datetime.format("%d/%m/%Y %T")
+                )
+            }
+            (Some(threshold_time), None) => {
+                let threshold_time_datetime: DateTime<Local> = threshold_time.into();
+                format!(
+                    "all entries older than {}",
+                    threshold_time_datetime.format("%d/%m/%Y %T")
+                )
+            }
+            (None, Some(max_size)) => {
+                format!("the oldest entries needed to fit under {max_size} bytes")
+            }
+            (None, None) => unreachable!("get_args requires a duration or a max size"),
+        };
+        let answer = input(&format!(
+            "{action} {criteria} in {path_str:?}. Enter \"y\" to confirm. "
+        ));
+        if answer != "y" {
+            Err(Error::Cancelled)?
+        }
+    }
+
+    let candidates =
+        pool.install(|| collect_candidates(&path, recursive, time_field, &include, &exclude))?;
+
+    let (age_selected, remaining): (Vec<Candidate>, Vec<Candidate>) = match threshold_time {
+        Some(threshold_time) => candidates
+            .into_iter()
+            .partition(|candidate| candidate.modified.lt(&threshold_time)),
+        None => (Vec::new(), candidates),
+    };
+
+    let mut old_candidates = age_selected;
+    if let Some(max_size) = max_size {
+        old_candidates.extend(size_trim(remaining, max_size));
+    }
+
+    if dry_run {
+        let mut reclaimable = 0u64;
+        for candidate in &old_candidates {
+            let age = SystemTime::now()
+                .duration_since(candidate.modified)
+                .unwrap_or_default();
+            let modified_datetime: DateTime<Local> = candidate.modified.into();
+            reclaimable += candidate.size;
+            info!(
+                "[dry-run] would remove {:?} ({time_field:?} {}, {} old, {} bytes)",
+                candidate.path,
+                modified_datetime.format("%d/%m/%Y %T"),
+                format_age(age),
+                candidate.size
+            );
+        }
+        info!(
+            "[dry-run] {} entries would be removed, reclaiming {reclaimable} bytes",
+            old_candidates.len()
+        );
+        return Ok(DeletionSummary {
+            succeeded: old_candidates.len(),
+            failures: Vec::new(),
+            previewed: true,
+        });
+    }
+
+    let old_paths: Vec<PathBuf> = old_candidates
+        .into_iter()
+        .map(|candidate| candidate.path)
         .collect();
-    let file_is_old_mapping = file_is_old_mapping?;
-
-    let results: Result<Vec<()>> = file_is_old_mapping
-        .iter()
-        .filter_map(|(file, is_old)| if *is_old { Some(file) } else { None })
-        .map(|file| {
-            let path = file.path();
-            if path.is_file() {
-                fs::remove_file(file.path()).or(Err(Error::DeleteFailed {
-                    filename: file.file_name(),
-                }))
-            } else if path.is_dir() {
-                fs::remove_dir_all(file.path()).or(Err(Error::DeleteFailed {
-                    filename: file.file_name(),
-                }))
-            } else {
-                Err(Error::DeleteFailed {
-                    filename: file.file_name(),
+
+    if purge {
+        let results: Vec<(PathBuf, Result<()>)> = pool.install(|| {
+            old_paths
+                .par_iter()
+                .map(|old_path| {
+                    let filename = old_path
+                        .file_name()
+                        .map(OsStr::to_owned)
+                        .unwrap_or_else(|| old_path.clone().into_os_string());
+
+                    info!("removing {old_path:?}");
+                    let result = if old_path.is_file() {
+                        fs::remove_file(old_path).or(Err(Error::DeleteFailed { filename }))
+                    } else if old_path.is_dir() {
+                        fs::remove_dir_all(old_path).or(Err(Error::DeleteFailed { filename }))
+                    } else {
+                        Err(Error::DeleteFailed { filename })
+                    };
+                    (old_path.clone(), result)
                 })
-            }
+                .collect()
+        });
+
+        let succeeded = results.iter().filter(|(_, result)| result.is_ok()).count();
+        let failures = results
+            .into_iter()
+            .filter_map(|(path, result)| result.err().map(|err| (path, err)))
+            .collect();
+
+        Ok(DeletionSummary {
+            succeeded,
+            failures,
+            previewed: false,
         })
-        .collect();
+    } else {
+        for old_path in &old_paths {
+            info!("trashing {old_path:?}");
+        }
+
+        match trash::delete_all(&old_paths) {
+            Ok(()) => Ok(DeletionSummary {
+                succeeded: old_paths.len(),
+                failures: Vec::new(),
+                previewed: false,
+            }),
+            Err(err) => {
+                // delete_all batches the whole call into a single Result, so a
+                // failure doesn't tell us which of the paths actually made it to
+                // the trash. Retry one at a time to get real per-file results.
+                warn!("batch trash of {path_str:?} failed ({err:?}), retrying entries individually");
 
-    Ok(results?.len())
+                let mut succeeded = 0;
+                let mut failures = Vec::new();
+                for old_path in old_paths {
+                    // An entry the failed batch already moved is gone from its path.
+                    if fs::symlink_metadata(&old_path).is_err() {
+                        succeeded += 1;
+                        continue;
+                    }
+                    match trash::delete(&old_path) {
+                        Ok(()) => succeeded += 1,
+                        Err(_) => {
+                            let filename = old_path
+                                .file_name()
+                                .map(OsStr::to_owned)
+                                .unwrap_or_else(|| old_path.clone().into_os_string());
+                            failures.push((old_path, Error::TrashFailed { filename }));
+                        }
+                    }
+                }
+
+                Ok(DeletionSummary {
+                    succeeded,
+                    failures,
+                    previewed: false,
+                })
+            }
+        }
+    }
 }
 
 fn main() {
-    let err = match main_script() {
-        Ok(files_count) => {
-            println!("Successfully removed {files_count} files!");
-            return;
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
+
+    let summary = match main_script() {
+        Ok(summary) => summary,
+        Err(err) => {
+            match err {
+                Error::InvalidArgument { name } => {
+                    error!("Invalid argument provided for argument {name}")
+                }
+                Error::ReadDirError { dirname } => error!("Failed to read directory {dirname:?}"),
+                Error::ReadFileError => error!("Failed to read file"),
+                Error::TimeSubtractionError => error!("Failed to subtract time"),
+                Error::DeleteFailed { filename } => error!("Failed to delete {filename:?}"),
+                Error::TrashFailed { filename } => {
+                    error!("Failed to move {filename:?} to the trash")
+                }
+                Error::PoolBuildFailed => error!("Failed to build the worker thread pool"),
+                Error::ReadDirEntryError => error!("Failed to read dir entry"),
+                Error::Cancelled => error!("Cancelled by user."),
+            }
+            std::process::exit(1);
         }
-        Err(err) => err,
     };
 
-    match err {
-        Error::InvalidArgument { name } => panic!("Invalid argument provided for argument {name}"),
-        Error::ReadDirError { dirname } => panic!("Failed to read directory {dirname:?}"),
-        Error::ReadFileError => panic!("Failed to read file"),
-        Error::TimeSubtractionError => panic!("Failed to subtract time"),
-        Error::DeleteFailed { filename } => panic!("Failed to delete {filename:?}"),
-        Error::ReadDirEntryError => panic!("Failed to read dir entry"),
-        Error::Cancelled => panic!("Cancelled by user."),
+    for (path, err) in &summary.failures {
+        error!("Failed to remove {path:?}: {err:?}");
+    }
+    if summary.previewed {
+        info!("Would have removed {} files (dry run)", summary.succeeded);
+    } else {
+        info!(
+            "Successfully removed {} files! ({} failed)",
+            summary.succeeded,
+            summary.failures.len()
+        );
+    }
+
+    if !summary.failures.is_empty() {
+        std::process::exit(1);
     }
 }